@@ -1,5 +1,6 @@
 use handlebars::Handlebars;
 use regex::Regex;
+use serde::Deserialize;
 use serde_json::json;
 use std::env;
 use std::fmt;
@@ -12,9 +13,15 @@ use structopt::StructOpt;
 use thiserror::Error;
 use which::which;
 
+/// Marker comment embedded in every plist rendered from the built-in templates
+/// (a `--template` plist carries it only if that template does), so `list` can
+/// tell our agents apart from third-party ones sharing `~/Library/LaunchAgents`.
+const LAUNCHIFY_MARKER: &str = "<!-- launchify:managed -->";
+
 const PLIST_TEMPLATE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
 <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\"
   \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">
+<!-- launchify:managed -->
 <plist version=\"1.0\">
 <dict>
     <key>Label</key>
@@ -32,9 +39,88 @@ const PLIST_TEMPLATE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
     <string>{{stderr}}</string>
     <key>WorkingDirectory</key>
     <string>{{working_dir}}</string>
-</dict>
+{{#if env}}    <key>EnvironmentVariables</key>
+    <dict>{{#each env}}
+        <key>{{@key}}</key>
+        <string>{{this}}</string>{{/each}}
+    </dict>
+{{/if}}{{#if run_at_load}}    <key>RunAtLoad</key>
+    <true/>
+{{/if}}{{#if keep_alive}}    <key>KeepAlive</key>
+    <true/>
+{{/if}}</dict>
 </plist>";
 
+const PLIST_CALENDAR_TEMPLATE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\"
+  \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">
+<!-- launchify:managed -->
+<plist version=\"1.0\">
+<dict>
+    <key>Label</key>
+    <string>{{name}}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{{program_path}}</string>{{#each args}}
+        <string>{{this}}</string>{{/each}}
+    </array>
+    <key>StartCalendarInterval</key>
+{{#if multiple}}    <array>{{#each calendar}}
+        <dict>{{#each this}}
+            <key>{{@key}}</key>
+            <integer>{{this}}</integer>{{/each}}
+        </dict>{{/each}}
+    </array>
+{{else}}    <dict>{{#each calendar.[0]}}
+        <key>{{@key}}</key>
+        <integer>{{this}}</integer>{{/each}}
+    </dict>
+{{/if}}    <key>StandardOutPath</key>
+    <string>{{stdout}}</string>
+    <key>StandardErrorPath</key>
+    <string>{{stderr}}</string>
+    <key>WorkingDirectory</key>
+    <string>{{working_dir}}</string>
+{{#if env}}    <key>EnvironmentVariables</key>
+    <dict>{{#each env}}
+        <key>{{@key}}</key>
+        <string>{{this}}</string>{{/each}}
+    </dict>
+{{/if}}{{#if run_at_load}}    <key>RunAtLoad</key>
+    <true/>
+{{/if}}{{#if keep_alive}}    <key>KeepAlive</key>
+    <true/>
+{{/if}}</dict>
+</plist>";
+
+const SERVICE_TEMPLATE: &str = "[Unit]
+Description=launchify job {{name}}
+# launchify:managed
+
+[Service]
+Type={{#if keep_alive}}simple{{else}}oneshot{{/if}}
+ExecStart={{exec_start}}
+WorkingDirectory={{working_dir}}
+StandardOutput=append:{{stdout}}
+StandardError=append:{{stderr}}
+{{#each env}}Environment={{@key}}={{this}}
+{{/each}}{{#if keep_alive}}Restart=always
+{{/if}}";
+
+const TIMER_TEMPLATE: &str = "[Unit]
+Description=launchify timer for {{name}}
+# launchify:managed
+
+[Timer]
+{{#if interval}}OnActiveSec={{interval}}s
+OnUnitActiveSec={{interval}}s
+{{/if}}{{#each on_calendar}}OnCalendar={{this}}
+{{/each}}Persistent=true
+
+[Install]
+WantedBy=timers.target
+";
+
 fn main() {
     let args = Cli::from_args();
 
@@ -72,26 +158,395 @@ enum RunError {
 
     #[error("could not load config file")]
     Load,
+
+    #[error("specify exactly one of a program argument or --config")]
+    InstallTarget,
+
+    #[error("--template renders a launchd plist and is not supported by the systemd backend")]
+    TemplateUnsupported,
+
+    #[error("launchctl unload failed")]
+    Unload,
+
+    #[error("no scheduled agent named {0}")]
+    NotFound(String),
+
+    #[error("invalid env var {0}, expected KEY=VALUE")]
+    InvalidEnv(String),
+
+    #[error("no schedule given: pass a period (e.g. 1h) or one or more --at specs")]
+    NoSchedule,
+
+    #[error("pass either a period or --at specs, not both")]
+    ConflictingSchedule,
+
+    #[error("could not parse config file {path}: {source}")]
+    Config {
+        path: String,
+        source: toml::de::Error,
+    },
+
+    #[error("job {job}: could not parse period {value:?}")]
+    JobConfig { job: String, value: String },
+}
+
+fn run(cli: Cli) -> Result<(), RunError> {
+    match cli {
+        Cli::Install(args) => run_install(&args),
+        Cli::List => run_list(),
+        Cli::Status { name } => run_status(&name),
+        Cli::Unload { name } => run_unload(&name),
+        Cli::Remove { name, logs } => run_remove(&name, logs),
+    }
+}
+
+fn run_install(args: &InstallArgs) -> Result<(), RunError> {
+    // Exactly one of a positional program or a --config manifest is required;
+    // the two modes are mutually exclusive.
+    match (&args.program, &args.config) {
+        (Some(_), Some(_)) | (None, None) => Err(RunError::InstallTarget),
+        (None, Some(config_path)) => run_manifest(config_path, args.dry_run),
+        (Some(_), None) => {
+            let cfg = LaunchConfig::from_cli(args)?;
+            install(&cfg, args.dry_run)
+        }
+    }
+}
+
+/// List the agents this tool installed. Dispatches to the backend matching the
+/// host platform so the workflow works on both macOS and Linux.
+fn run_list() -> Result<(), RunError> {
+    if cfg!(target_os = "macos") {
+        launchd_list()
+    } else {
+        systemd_list()
+    }
+}
+
+/// Show the state of one agent, dispatched to the host platform's backend.
+fn run_status(name: &str) -> Result<(), RunError> {
+    if cfg!(target_os = "macos") {
+        launchd_status(name)
+    } else {
+        systemd_status(name)
+    }
+}
+
+/// Unload/disable an agent, dispatched to the host platform's backend.
+fn run_unload(name: &str) -> Result<(), RunError> {
+    if cfg!(target_os = "macos") {
+        launchd_unload(name)
+    } else {
+        systemd_unload(name)
+    }
+}
+
+/// Unload/disable an agent and delete its unit file(s), dispatched to the host
+/// platform's backend.
+fn run_remove(name: &str, logs: bool) -> Result<(), RunError> {
+    if cfg!(target_os = "macos") {
+        launchd_remove(name, logs)
+    } else {
+        systemd_remove(name, logs)
+    }
 }
 
-fn run(args: Cli) -> Result<(), RunError> {
-    let cfg = LaunchConfig::from_cli(&args)?;
-    let plist_file = PlistFile::from(&cfg)?;
+/// List the agents this tool installed, annotated with their loaded state
+/// and last exit code as reported by `launchctl list`.
+fn launchd_list() -> Result<(), RunError> {
+    let plist_dir = LaunchDirs::agents_dir()?;
+    let loaded = launchctl_list()?;
+
+    let mut found = false;
+    for entry in fs::read_dir(&plist_dir)? {
+        let entry = entry?;
+        let filename = entry.file_name();
+        let filename = match filename.to_str() {
+            Some(f) => f,
+            None => continue,
+        };
+
+        let name = match agent_name(filename) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        // Skip plists we didn't write (system and third-party agents live in
+        // the same directory); ours carry the launchify marker comment.
+        match fs::read_to_string(entry.path()) {
+            Ok(contents) if contents.contains(LAUNCHIFY_MARKER) => {}
+            _ => continue,
+        }
+        found = true;
+
+        match loaded.get(name) {
+            Some(status) => println!("{}\tloaded\tlast exit: {}", name, status),
+            None => println!("{}\tnot loaded", name),
+        }
+    }
+
+    if !found {
+        println!("no scheduled agents installed");
+    }
+
+    Ok(())
+}
+
+/// Show the loaded state and last exit code for a single agent.
+fn launchd_status(name: &str) -> Result<(), RunError> {
+    let loaded = launchctl_list()?;
+    match loaded.get(name) {
+        Some(status) => println!("{}\tloaded\tlast exit: {}", name, status),
+        None => println!("{}\tnot loaded", name),
+    }
+    Ok(())
+}
+
+/// Unload an agent from launchd without deleting its plist.
+fn launchd_unload(name: &str) -> Result<(), RunError> {
+    unload(name)?;
+    println!("unloaded {}", name);
+    Ok(())
+}
+
+/// Unload an agent and delete its plist, optionally removing its logs too.
+fn launchd_remove(name: &str, logs: bool) -> Result<(), RunError> {
+    let dirs = LaunchDirs::from(name)?;
+    let plist_path = plist_filepath(name)?;
+
+    if plist_path.exists() {
+        unload(name)?;
+        fs::remove_file(&plist_path)?;
+    } else {
+        return Err(RunError::NotFound(name.to_owned()));
+    }
+
+    if logs && dirs.log_dir.exists() {
+        fs::remove_dir_all(&dirs.log_dir)?;
+    }
+
+    println!("removed {}", name);
+    Ok(())
+}
+
+/// The token embedded in every unit file this tool writes so the lifecycle
+/// commands can tell our units apart from hand-written ones.
+const MANAGED_TOKEN: &str = "launchify:managed";
+
+/// List the systemd timers this tool installed, annotated with their active
+/// and enabled state as reported by `systemctl --user`.
+fn systemd_list() -> Result<(), RunError> {
+    let dir = LaunchDirs::systemd_user_dir()?;
+
+    let mut found = false;
+    if dir.exists() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let filename = entry.file_name();
+            let filename = match filename.to_str() {
+                Some(f) => f,
+                None => continue,
+            };
+
+            let name = match filename.strip_suffix(".timer") {
+                Some(name) => name,
+                None => continue,
+            };
+
+            // Only report units carrying our marker comment.
+            match fs::read_to_string(entry.path()) {
+                Ok(contents) if contents.contains(MANAGED_TOKEN) => {}
+                _ => continue,
+            }
+            found = true;
 
-    if args.dry_run {
-        println!("Dry run: would write {}", plist_file);
+            println!("{}\t{}", name, systemctl_state(name));
+        }
+    }
+
+    if !found {
+        println!("no scheduled agents installed");
+    }
+
+    Ok(())
+}
+
+/// Show the active and enabled state for a single systemd timer.
+fn systemd_status(name: &str) -> Result<(), RunError> {
+    println!("{}\t{}", name, systemctl_state(name));
+    Ok(())
+}
+
+/// Query `systemctl --user is-active`/`is-enabled` for a timer, returning a
+/// tab-separated "<active>\t<enabled>" summary.
+fn systemctl_state(name: &str) -> String {
+    let unit = format!("{}.timer", name);
+    let query = |verb: &str| -> String {
+        match Command::new("systemctl")
+            .args(&["--user", verb, &unit])
+            .output()
+        {
+            Ok(out) => String::from_utf8_lossy(&out.stdout).trim().to_owned(),
+            Err(_) => "unknown".to_owned(),
+        }
+    };
+    format!("{}\t{}", query("is-active"), query("is-enabled"))
+}
+
+/// Disable and stop a systemd timer without deleting its unit files.
+fn systemd_unload(name: &str) -> Result<(), RunError> {
+    systemctl_disable(name)?;
+    println!("unloaded {}", name);
+    Ok(())
+}
+
+/// Disable a systemd timer and delete its unit files, optionally its logs too.
+fn systemd_remove(name: &str, logs: bool) -> Result<(), RunError> {
+    let dir = LaunchDirs::systemd_user_dir()?;
+    let timer = dir.join(format!("{}.timer", name));
+    let service = dir.join(format!("{}.service", name));
+
+    if !timer.exists() && !service.exists() {
+        return Err(RunError::NotFound(name.to_owned()));
+    }
+
+    systemctl_disable(name)?;
+    if timer.exists() {
+        fs::remove_file(&timer)?;
+    }
+    if service.exists() {
+        fs::remove_file(&service)?;
+    }
+
+    let dirs = LaunchDirs::from(name)?;
+    if logs && dirs.log_dir.exists() {
+        fs::remove_dir_all(&dirs.log_dir)?;
+    }
+
+    println!("removed {}", name);
+    Ok(())
+}
+
+/// Run `systemctl --user disable --now` on the timer for `name`.
+fn systemctl_disable(name: &str) -> Result<(), RunError> {
+    let unit = format!("{}.timer", name);
+    let status = Command::new("systemctl")
+        .args(&["--user", "disable", "--now", &unit])
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(RunError::Unload)
+    }
+}
+
+/// Run `launchctl unload -w` on the plist for `name`.
+fn unload(name: &str) -> Result<(), RunError> {
+    let plist_path = plist_filepath(name)?;
+    let filepath = plist_path.to_str().ok_or(RunError::InvalidFilepath)?;
+
+    let status = Command::new("launchctl")
+        .args(&["unload", "-w", filepath])
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(RunError::Unload)
+    }
+}
+
+/// Parse `launchctl list` into a map of label to last-exit status. The output
+/// is three tab-separated columns: PID, last exit status, and label.
+fn launchctl_list() -> Result<std::collections::HashMap<String, String>, RunError> {
+    let output = Command::new("launchctl").arg("list").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut map = std::collections::HashMap::new();
+    for line in stdout.lines().skip(1) {
+        let mut cols = line.split('\t');
+        let _pid = cols.next();
+        let status = cols.next().unwrap_or("-").to_owned();
+        if let Some(label) = cols.next() {
+            map.insert(label.to_owned(), status);
+        }
+    }
+
+    Ok(map)
+}
+
+/// The absolute path of the plist this tool writes for `name`.
+fn plist_filepath(name: &str) -> Result<PathBuf, RunError> {
+    let mut filepath = LaunchDirs::agents_dir()?;
+    filepath.push(format!("com.{}.plist", name));
+    Ok(filepath)
+}
+
+/// Recover an agent name from one of our `com.<name>.plist` filenames.
+fn agent_name(filename: &str) -> Option<&str> {
+    filename
+        .strip_prefix("com.")
+        .and_then(|rest| rest.strip_suffix(".plist"))
+}
+
+/// Parse repeated `KEY=VALUE` strings into an ordered list of pairs.
+fn parse_env(pairs: &[String]) -> Result<Vec<(String, String)>, RunError> {
+    pairs
+        .iter()
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .ok_or_else(|| RunError::InvalidEnv(pair.to_owned()))
+        })
+        .collect()
+}
+
+/// Install every job described by a TOML manifest. A failure scheduling one
+/// job is reported but does not abort the rest of the batch.
+fn run_manifest(path: &str, dry_run: bool) -> Result<(), RunError> {
+    let contents = fs::read_to_string(path)?;
+    let manifest: Manifest = toml::from_str(&contents).map_err(|source| RunError::Config {
+        path: path.to_owned(),
+        source,
+    })?;
+
+    for spec in &manifest.jobs {
+        match LaunchConfig::from_job_spec(spec).and_then(|cfg| install(&cfg, dry_run)) {
+            Ok(()) => {}
+            Err(err) => println!("failed to schedule {}: {}", spec.program, err),
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the unit file(s) for a single config and register them with the host
+/// platform's scheduler, or just describe what would happen when `dry_run` is
+/// set.
+fn install(cfg: &LaunchConfig, dry_run: bool) -> Result<(), RunError> {
+    let backend = backend();
+    let units = backend.render(cfg)?;
+
+    if dry_run {
+        for unit in &units {
+            println!("Dry run: would write {}", unit);
+        }
         return Ok(());
     }
 
-    cfg.dirs.ensure()?;
-    plist_file.write()?;
-    plist_file.load()?;
+    fs::create_dir_all(&cfg.dirs.log_dir)?;
+    fs::create_dir_all(backend.install_path()?)?;
+    for unit in &units {
+        unit.write()?;
+    }
+    backend.activate(cfg)?;
     println!("successfuly scheduled {}", cfg.name);
 
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Period {
     Day(u64),
     Hour(u64),
@@ -150,10 +605,193 @@ impl FromStr for Period {
     }
 }
 
+/// How often launchd should fire the job: either a fixed interval
+/// (`StartInterval`) or one or more wall-clock times (`StartCalendarInterval`).
+#[derive(Debug)]
+enum Schedule {
+    Interval(Period),
+    Calendar(Vec<CalendarSchedule>),
+}
+
+/// A single launchd `StartCalendarInterval` entry. Any field left `None`
+/// matches every value of that unit, mirroring `*` in a cron expression.
+#[derive(Debug, Default, Clone)]
+struct CalendarSchedule {
+    minute: Option<u32>,
+    hour: Option<u32>,
+    day: Option<u32>,
+    weekday: Option<u32>,
+    month: Option<u32>,
+}
+
+impl CalendarSchedule {
+    /// Render the set fields as a JSON object using launchd's plist key
+    /// names, so the template can emit one `<key>`/`<integer>` pair each.
+    fn to_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        for (key, value) in [
+            ("Minute", self.minute),
+            ("Hour", self.hour),
+            ("Day", self.day),
+            ("Weekday", self.weekday),
+            ("Month", self.month),
+        ] {
+            if let Some(v) = value {
+                map.insert(key.to_owned(), json!(v));
+            }
+        }
+        serde_json::Value::Object(map)
+    }
+
+    /// Render the set fields as a systemd `OnCalendar=` expression. Unset
+    /// fields become `*`, and a weekday is emitted as its three-letter name.
+    fn to_systemd(&self) -> String {
+        let field = |v: Option<u32>| match v {
+            Some(n) => format!("{:02}", n),
+            None => "*".to_owned(),
+        };
+
+        let date = format!("*-{}-{}", field(self.month), field(self.day));
+        let time = format!("{}:{}:00", field(self.hour), field(self.minute));
+
+        match self.weekday {
+            Some(w) => format!("{} {} {}", weekday_name(w), date, time),
+            None => format!("{} {}", date, time),
+        }
+    }
+}
+
+/// The systemd three-letter name for a cron weekday (0 or 7 are both Sunday).
+fn weekday_name(weekday: u32) -> &'static str {
+    match weekday % 7 {
+        0 => "Sun",
+        1 => "Mon",
+        2 => "Tue",
+        3 => "Wed",
+        4 => "Thu",
+        5 => "Fri",
+        _ => "Sat",
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseCalendarError(String);
+
+impl fmt::Display for ParseCalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error parsing calendar schedule {}", self.0)
+    }
+}
+
+impl FromStr for CalendarSchedule {
+    type Err = ParseCalendarError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // Two accepted forms: a 5-field cron string ("0 9 * * 1") or a list
+        // of `key=value` pairs ("min=0 hour=9 weekday=1").
+        if s.contains('=') {
+            Self::from_pairs(s)
+        } else {
+            Self::from_cron(s)
+        }
+    }
+}
+
+impl CalendarSchedule {
+    fn from_cron(s: &str) -> std::result::Result<Self, ParseCalendarError> {
+        let fields: Vec<&str> = s.split_ascii_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(ParseCalendarError(s.to_owned()));
+        }
+
+        let parse = |field: &str| -> std::result::Result<Option<u32>, ParseCalendarError> {
+            if field == "*" {
+                Ok(None)
+            } else {
+                field
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| ParseCalendarError(s.to_owned()))
+            }
+        };
+
+        // cron order: minute hour day-of-month month day-of-week.
+        Ok(Self {
+            minute: parse(fields[0])?,
+            hour: parse(fields[1])?,
+            day: parse(fields[2])?,
+            month: parse(fields[3])?,
+            weekday: parse(fields[4])?,
+        })
+    }
+
+    fn from_pairs(s: &str) -> std::result::Result<Self, ParseCalendarError> {
+        let mut schedule = Self::default();
+
+        for pair in s.split_ascii_whitespace() {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| ParseCalendarError(s.to_owned()))?;
+
+            let value = if value == "*" {
+                None
+            } else {
+                Some(
+                    value
+                        .parse()
+                        .map_err(|_| ParseCalendarError(s.to_owned()))?,
+                )
+            };
+
+            match key {
+                "min" | "minute" => schedule.minute = value,
+                "hour" => schedule.hour = value,
+                "day" => schedule.day = value,
+                "weekday" => schedule.weekday = value,
+                "month" => schedule.month = value,
+                _ => return Err(ParseCalendarError(s.to_owned())),
+            }
+        }
+
+        Ok(schedule)
+    }
+}
+
 #[derive(StructOpt)]
-struct Cli {
-    period: Period,
-    program: String,
+enum Cli {
+    /// Schedule one job (or a whole manifest with --config).
+    Install(InstallArgs),
+
+    /// List the agents this tool installed and their loaded state.
+    List,
+
+    /// Show the state of one agent (launchd: loaded state and last exit code;
+    /// systemd: active and enabled state).
+    Status { name: String },
+
+    /// Unload an agent from its scheduler, leaving its unit file(s) in place.
+    Unload { name: String },
+
+    /// Unload an agent and delete its unit file(s) (and optionally its logs).
+    Remove {
+        name: String,
+
+        /// Also delete the agent's log directory.
+        #[structopt(long)]
+        logs: bool,
+    },
+}
+
+#[derive(StructOpt)]
+struct InstallArgs {
+    program: Option<String>,
+    period: Option<Period>,
+
+    /// A wall-clock fire time, as a 5-field cron string ("0 9 * * 1") or
+    /// space-separated `key=value` pairs ("min=0 hour=9 weekday=1"). Repeat
+    /// to schedule several fire times.
+    #[structopt(long)]
+    at: Vec<CalendarSchedule>,
 
     #[structopt(long)]
     dry_run: bool,
@@ -166,28 +804,153 @@ struct Cli {
 
     #[structopt(long)]
     working_dir: Option<String>,
+
+    /// A `KEY=VALUE` environment variable for the job. Repeat for several.
+    #[structopt(long)]
+    env: Vec<String>,
+
+    /// Run the job once as soon as it is loaded (launchd `RunAtLoad`).
+    #[structopt(long)]
+    run_at_load: bool,
+
+    /// Restart the job whenever it exits (launchd `KeepAlive`).
+    #[structopt(long)]
+    keep_alive: bool,
+
+    /// Render the plist from a custom Handlebars template instead of the
+    /// built-in one. It receives the full config as its JSON context.
+    #[structopt(long)]
+    template: Option<String>,
+
+    /// Install every job described by a TOML manifest instead of a single
+    /// job from the command line.
+    #[structopt(long)]
+    config: Option<String>,
+}
+
+/// A TOML manifest describing a batch of jobs to install in one run.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    #[serde(default, rename = "job")]
+    jobs: Vec<JobSpec>,
+}
+
+/// A single job entry in a [`Manifest`].
+#[derive(Debug, Deserialize)]
+struct JobSpec {
+    program: String,
+    period: String,
+
+    #[serde(default)]
+    name: Option<String>,
+
+    #[serde(default)]
+    args: Vec<String>,
+
+    #[serde(default)]
+    working_dir: Option<String>,
+
+    #[serde(default)]
+    stdout: Option<String>,
+
+    #[serde(default)]
+    stderr: Option<String>,
+
+    #[serde(default)]
+    env: std::collections::BTreeMap<String, String>,
 }
 
 struct LaunchConfig {
     name: String,
     program_path: PathBuf,
-    start_interval: u64,
+    schedule: Schedule,
     dirs: LaunchDirs,
     args: Vec<String>,
     working_dir: String,
+    stdout: Option<String>,
+    stderr: Option<String>,
+    env: Vec<(String, String)>,
+    run_at_load: bool,
+    keep_alive: bool,
+    template: Option<String>,
 }
 
 impl LaunchConfig {
-    fn from_cli(args: &Cli) -> Result<Self, RunError> {
+    fn from_cli(args: &InstallArgs) -> Result<Self, RunError> {
+        let schedule = match (&args.period, args.at.is_empty()) {
+            // A period and --at are two different cadences; refuse rather than
+            // silently pick one and schedule on an unintended schedule.
+            (Some(_), false) => return Err(RunError::ConflictingSchedule),
+            (_, false) => Schedule::Calendar(args.at.clone()),
+            (Some(period), true) => Schedule::Interval(period.clone()),
+            (None, true) => return Err(RunError::NoSchedule),
+        };
+
+        let program_args = match &args.args {
+            Some(a) => a.split_ascii_whitespace().map(|s| s.to_string()).collect(),
+            None => Vec::new(),
+        };
+
+        let program = args.program.as_deref().ok_or(RunError::InstallTarget)?;
+
+        let mut cfg = Self::build(
+            program,
+            args.name.as_deref(),
+            schedule,
+            program_args,
+            args.working_dir.as_deref(),
+            None,
+            None,
+        )?;
+
+        cfg.env = parse_env(&args.env)?;
+        cfg.run_at_load = args.run_at_load;
+        cfg.keep_alive = args.keep_alive;
+        cfg.template = args.template.clone();
+
+        Ok(cfg)
+    }
+
+    fn from_job_spec(spec: &JobSpec) -> Result<Self, RunError> {
+        let period: Period = spec.period.parse().map_err(|_| RunError::JobConfig {
+            job: spec.name.clone().unwrap_or_else(|| spec.program.clone()),
+            value: spec.period.clone(),
+        })?;
+
+        let mut cfg = Self::build(
+            &spec.program,
+            spec.name.as_deref(),
+            Schedule::Interval(period),
+            spec.args.clone(),
+            spec.working_dir.as_deref(),
+            spec.stdout.clone(),
+            spec.stderr.clone(),
+        )?;
+
+        cfg.env = spec.env.clone().into_iter().collect();
+
+        Ok(cfg)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build(
+        program: &str,
+        name: Option<&str>,
+        schedule: Schedule,
+        args: Vec<String>,
+        working_dir: Option<&str>,
+        stdout: Option<String>,
+        stderr: Option<String>,
+    ) -> Result<Self, RunError> {
         // First, try and treat the program as a filepath and see if we can
         // get the absolute path. Otherwise, we use the which crate to see
         // if the program matches an executable on the current PATH.
-        let path = match fs::canonicalize(&args.program) {
+        let path = match fs::canonicalize(program) {
             Ok(path) => path,
-            Err(_) => which(&args.program).map_err(|_| RunError::InvalidProg)?,
+            Err(_) => which(program).map_err(|_| RunError::InvalidProg)?,
         };
 
-        let name = match &args.name {
+        let name = match name {
             Some(name) => name.to_owned(),
             None => path
                 .file_stem()
@@ -197,15 +960,9 @@ impl LaunchConfig {
                 .to_owned(),
         };
 
-        let start_interval = args.period.to_seconds();
         let dirs = LaunchDirs::from(&name)?;
 
-        let program_args = match &args.args {
-            Some(a) => a.split_ascii_whitespace().map(|s| s.to_string()).collect(),
-            None => Vec::new(),
-        };
-
-        let working_dir = match &args.working_dir {
+        let working_dir = match working_dir {
             Some(dir) => dir.to_owned(),
             None => env::current_dir()
                 .map_err(|_| RunError::CurrentDir)?
@@ -217,10 +974,16 @@ impl LaunchConfig {
         Ok(Self {
             name,
             program_path: path,
-            start_interval,
+            schedule,
             dirs,
-            args: program_args,
+            args,
             working_dir,
+            stdout,
+            stderr,
+            env: Vec::new(),
+            run_at_load: false,
+            keep_alive: false,
+            template: None,
         })
     }
 
@@ -229,25 +992,130 @@ impl LaunchConfig {
             .program_path
             .to_str()
             .ok_or(RunError::InvalidFilepath)?;
-        let stdout_path = self.log_path("stdout")?;
-        let stderr_path = self.log_path("stderr")?;
+        let stdout_path = match &self.stdout {
+            Some(path) => path.to_owned(),
+            None => self.log_path("stdout")?,
+        };
+        let stderr_path = match &self.stderr {
+            Some(path) => path.to_owned(),
+            None => self.log_path("stderr")?,
+        };
 
         let reg = Handlebars::new();
-        reg.render_template(
-            PLIST_TEMPLATE,
-            &json!(
-                {
-                    "name": self.name,
-                    "program_path": program_path,
-                    "args": self.args,
-                    "interval": self.start_interval,
-                    "stdout": stdout_path,
-                    "stderr": stderr_path,
-                    "working_dir": self.working_dir,
-                }
+        let (builtin_template, schedule_ctx) = match &self.schedule {
+            Schedule::Interval(period) => (
+                PLIST_TEMPLATE,
+                json!({ "interval": period.to_seconds() }),
             ),
-        )
-        .map_err(|e| e.into())
+            Schedule::Calendar(entries) => {
+                let calendar: Vec<serde_json::Value> =
+                    entries.iter().map(CalendarSchedule::to_json).collect();
+                (
+                    PLIST_CALENDAR_TEMPLATE,
+                    json!({ "calendar": calendar, "multiple": entries.len() > 1 }),
+                )
+            }
+        };
+
+        let env: serde_json::Map<String, serde_json::Value> = self
+            .env
+            .iter()
+            .map(|(k, v)| (k.clone(), json!(v)))
+            .collect();
+
+        let mut ctx = json!({
+            "name": self.name,
+            "program_path": program_path,
+            "args": self.args,
+            "stdout": stdout_path,
+            "stderr": stderr_path,
+            "working_dir": self.working_dir,
+            "env": env,
+            "run_at_load": self.run_at_load,
+            "keep_alive": self.keep_alive,
+        });
+        if let (Some(obj), Some(extra)) = (ctx.as_object_mut(), schedule_ctx.as_object()) {
+            obj.extend(extra.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+
+        // A user-supplied template receives the same full context, so any
+        // launchd key the tool doesn't model natively can still be emitted.
+        match &self.template {
+            Some(path) => {
+                let template = fs::read_to_string(path)?;
+                reg.render_template(&template, &ctx).map_err(|e| e.into())
+            }
+            None => reg
+                .render_template(builtin_template, &ctx)
+                .map_err(|e| e.into()),
+        }
+    }
+
+    /// Render the `*.service` and `*.timer` unit bodies for the systemd
+    /// backend, returned as `(service, timer)`.
+    fn systemd_units(&self) -> Result<(String, String), RunError> {
+        // A custom template is written against the launchd plist context and
+        // has no systemd equivalent; reject it rather than silently ignore it.
+        if self.template.is_some() {
+            return Err(RunError::TemplateUnsupported);
+        }
+
+        let program_path = self
+            .program_path
+            .to_str()
+            .ok_or(RunError::InvalidFilepath)?;
+        let stdout_path = match &self.stdout {
+            Some(path) => path.to_owned(),
+            None => self.log_path("stdout")?,
+        };
+        let stderr_path = match &self.stderr {
+            Some(path) => path.to_owned(),
+            None => self.log_path("stderr")?,
+        };
+
+        let mut exec_start = program_path.to_owned();
+        for arg in &self.args {
+            exec_start.push(' ');
+            exec_start.push_str(arg);
+        }
+
+        // Unit files are not HTML, so keep values verbatim.
+        let mut reg = Handlebars::new();
+        reg.register_escape_fn(handlebars::no_escape);
+
+        let env: serde_json::Map<String, serde_json::Value> = self
+            .env
+            .iter()
+            .map(|(k, v)| (k.clone(), json!(v)))
+            .collect();
+
+        let service = reg.render_template(
+            SERVICE_TEMPLATE,
+            &json!({
+                "name": self.name,
+                "exec_start": exec_start,
+                "working_dir": self.working_dir,
+                "stdout": stdout_path,
+                "stderr": stderr_path,
+                "env": env,
+                "keep_alive": self.keep_alive,
+            }),
+        )?;
+
+        let timer_ctx = match &self.schedule {
+            Schedule::Interval(period) => json!({
+                "name": self.name,
+                "interval": period.to_seconds(),
+            }),
+            Schedule::Calendar(entries) => {
+                let on_calendar: Vec<String> =
+                    entries.iter().map(CalendarSchedule::to_systemd).collect();
+                json!({ "name": self.name, "on_calendar": on_calendar })
+            }
+        };
+        let timer = reg.render_template(TIMER_TEMPLATE, &timer_ctx)?;
+
+        Ok((service, timer))
     }
 
     fn log_path(&self, filename: &str) -> Result<String, RunError> {
@@ -259,60 +1127,110 @@ impl LaunchConfig {
     }
 
     fn plist_filepath(&self) -> Result<PathBuf, RunError> {
-        let filename = format!("com.{}.plist", self.name);
-        let mut filepath = dirs::home_dir().ok_or(RunError::NoHomeDir)?;
-
-        filepath.push("Library");
-        filepath.push("LaunchAgents");
-        filepath.push(filename);
-        Ok(filepath)
+        plist_filepath(&self.name)
     }
 }
 
 struct LaunchDirs {
     log_dir: PathBuf,
-    plist_dir: PathBuf,
 }
 
 impl LaunchDirs {
     fn from(name: &str) -> Result<Self, RunError> {
         let mut log_dir = dirs::home_dir().ok_or(RunError::NoHomeDir)?;
-        let mut plist_dir = log_dir.clone();
 
         log_dir.push("logs");
         log_dir.push(name);
 
-        plist_dir.push("Library");
-        plist_dir.push("LaunchAgents");
+        Ok(Self { log_dir })
+    }
 
-        Ok(Self { log_dir, plist_dir })
+    /// The shared `~/Library/LaunchAgents` directory where launchd looks for
+    /// per-user agent plists.
+    fn agents_dir() -> Result<PathBuf, RunError> {
+        let mut dir = dirs::home_dir().ok_or(RunError::NoHomeDir)?;
+        dir.push("Library");
+        dir.push("LaunchAgents");
+        Ok(dir)
     }
 
-    fn ensure(&self) -> io::Result<()> {
-        fs::create_dir_all(&self.log_dir)?;
-        fs::create_dir_all(&self.plist_dir)?;
-        Ok(())
+    /// The `~/.config/systemd/user` directory where systemd looks for per-user
+    /// service and timer units.
+    fn systemd_user_dir() -> Result<PathBuf, RunError> {
+        let mut dir = dirs::config_dir().ok_or(RunError::NoHomeDir)?;
+        dir.push("systemd");
+        dir.push("user");
+        Ok(dir)
     }
 }
 
-struct PlistFile {
+/// A single config file produced by a [`SchedulerBackend`], together with the
+/// absolute path it should be written to.
+struct RenderedUnit {
     filepath: PathBuf,
     contents: String,
 }
 
-impl PlistFile {
-    fn from(cfg: &LaunchConfig) -> Result<Self, RunError> {
-        let filepath = cfg.plist_filepath()?;
-        let contents = cfg.plist_contents()?;
-        Ok(Self { filepath, contents })
-    }
-
+impl RenderedUnit {
     fn write(&self) -> io::Result<()> {
         fs::write(&self.filepath, &self.contents)
     }
+}
 
-    fn load(&self) -> Result<(), RunError> {
-        let filepath = self.filepath.to_str().ok_or(RunError::InvalidFilepath)?;
+impl fmt::Display for RenderedUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let filepath = match self.filepath.to_str() {
+            Some(path) => path,
+            None => return Err(fmt::Error),
+        };
+
+        write!(f, "{}:\n{}", filepath, self.contents)
+    }
+}
+
+/// A platform scheduler: it knows how to render a [`LaunchConfig`] into the
+/// unit file(s) that platform expects, where those files live, and how to
+/// register the job once they are written.
+trait SchedulerBackend {
+    /// Render the file(s) describing this job.
+    fn render(&self, cfg: &LaunchConfig) -> Result<Vec<RenderedUnit>, RunError>;
+
+    /// The directory the rendered file(s) are installed into.
+    fn install_path(&self) -> Result<PathBuf, RunError>;
+
+    /// Register the job with the platform scheduler.
+    fn activate(&self, cfg: &LaunchConfig) -> Result<(), RunError>;
+}
+
+/// Pick the backend for the host platform. macOS uses launchd; everything
+/// else is assumed to be a systemd-based Linux.
+fn backend() -> Box<dyn SchedulerBackend> {
+    if cfg!(target_os = "macos") {
+        Box::new(LaunchdBackend)
+    } else {
+        Box::new(SystemdBackend)
+    }
+}
+
+/// macOS backend: a single plist in `~/Library/LaunchAgents`, activated with
+/// `launchctl load -w`.
+struct LaunchdBackend;
+
+impl SchedulerBackend for LaunchdBackend {
+    fn render(&self, cfg: &LaunchConfig) -> Result<Vec<RenderedUnit>, RunError> {
+        Ok(vec![RenderedUnit {
+            filepath: cfg.plist_filepath()?,
+            contents: cfg.plist_contents()?,
+        }])
+    }
+
+    fn install_path(&self) -> Result<PathBuf, RunError> {
+        LaunchDirs::agents_dir()
+    }
+
+    fn activate(&self, cfg: &LaunchConfig) -> Result<(), RunError> {
+        let plist_path = cfg.plist_filepath()?;
+        let filepath = plist_path.to_str().ok_or(RunError::InvalidFilepath)?;
         let status = Command::new("launchctl")
             .args(&["load", "-w", filepath])
             .status()?;
@@ -325,13 +1243,176 @@ impl PlistFile {
     }
 }
 
-impl fmt::Display for PlistFile {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let filepath = match self.filepath.to_str() {
-            Some(path) => path,
-            None => return Err(fmt::Error),
+/// Linux backend: a paired `*.service` + `*.timer` in
+/// `~/.config/systemd/user`, activated with `systemctl --user enable --now`.
+struct SystemdBackend;
+
+impl SchedulerBackend for SystemdBackend {
+    fn render(&self, cfg: &LaunchConfig) -> Result<Vec<RenderedUnit>, RunError> {
+        let dir = self.install_path()?;
+        let (service, timer) = cfg.systemd_units()?;
+
+        Ok(vec![
+            RenderedUnit {
+                filepath: dir.join(format!("{}.service", cfg.name)),
+                contents: service,
+            },
+            RenderedUnit {
+                filepath: dir.join(format!("{}.timer", cfg.name)),
+                contents: timer,
+            },
+        ])
+    }
+
+    fn install_path(&self) -> Result<PathBuf, RunError> {
+        LaunchDirs::systemd_user_dir()
+    }
+
+    fn activate(&self, cfg: &LaunchConfig) -> Result<(), RunError> {
+        let unit = format!("{}.timer", cfg.name);
+        let status = Command::new("systemctl")
+            .args(&["--user", "enable", "--now", &unit])
+            .status()?;
+
+        if !status.success() {
+            return Err(RunError::Load);
+        }
+
+        // launchd's RunAtLoad runs the job once as soon as it is registered;
+        // a systemd timer only fires on its next trigger, so start the service
+        // immediately to match that behaviour.
+        if cfg.run_at_load {
+            let service = format!("{}.service", cfg.name);
+            let status = Command::new("systemctl")
+                .args(&["--user", "start", &service])
+                .status()?;
+            if !status.success() {
+                return Err(RunError::Load);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cron_maps_fields_in_launchd_order() {
+        // cron order is minute hour day-of-month month day-of-week.
+        let sched = CalendarSchedule::from_cron("0 9 1 6 2").unwrap();
+        assert_eq!(sched.minute, Some(0));
+        assert_eq!(sched.hour, Some(9));
+        assert_eq!(sched.day, Some(1));
+        assert_eq!(sched.month, Some(6));
+        assert_eq!(sched.weekday, Some(2));
+    }
+
+    #[test]
+    fn cron_star_becomes_none() {
+        let sched = CalendarSchedule::from_cron("0 9 * * 1").unwrap();
+        assert_eq!(sched.minute, Some(0));
+        assert_eq!(sched.hour, Some(9));
+        assert_eq!(sched.day, None);
+        assert_eq!(sched.month, None);
+        assert_eq!(sched.weekday, Some(1));
+    }
+
+    #[test]
+    fn cron_rejects_wrong_field_count_or_value() {
+        assert!(CalendarSchedule::from_cron("0 9 * *").is_err());
+        assert!(CalendarSchedule::from_cron("0 9 x * 1").is_err());
+    }
+
+    #[test]
+    fn pairs_accept_minute_alias_and_star() {
+        let sched = CalendarSchedule::from_pairs("min=30 hour=* weekday=1").unwrap();
+        assert_eq!(sched.minute, Some(30));
+        assert_eq!(sched.hour, None);
+        assert_eq!(sched.weekday, Some(1));
+        assert_eq!(sched.day, None);
+    }
+
+    #[test]
+    fn pairs_reject_unknown_key() {
+        assert!(CalendarSchedule::from_pairs("second=5").is_err());
+    }
+
+    #[test]
+    fn only_set_fields_render_to_json() {
+        let sched = CalendarSchedule {
+            hour: Some(9),
+            weekday: Some(1),
+            ..Default::default()
         };
+        let value = sched.to_json();
+        let obj = value.as_object().unwrap();
+        assert_eq!(obj.len(), 2);
+        assert_eq!(obj.get("Hour"), Some(&json!(9)));
+        assert_eq!(obj.get("Weekday"), Some(&json!(1)));
+        assert!(!obj.contains_key("Minute"));
+    }
 
-        write!(f, "{}:\n{}", filepath, self.contents)
+    #[test]
+    fn systemd_expression_names_weekday_and_stars_unset() {
+        let sched = CalendarSchedule::from_cron("0 9 * * 1").unwrap();
+        assert_eq!(sched.to_systemd(), "Mon *-*-* 09:00:00");
+
+        // No weekday means no leading day-of-week token.
+        let daily = CalendarSchedule::from_cron("0 9 * * *").unwrap();
+        assert_eq!(daily.to_systemd(), "*-*-* 09:00:00");
+    }
+
+    #[test]
+    fn sunday_is_seven_or_zero() {
+        assert_eq!(weekday_name(0), "Sun");
+        assert_eq!(weekday_name(7), "Sun");
+    }
+
+    #[test]
+    fn parse_env_splits_key_value() {
+        let env = parse_env(&["FOO=bar".to_owned(), "BAZ=qux=quux".to_owned()]).unwrap();
+        assert_eq!(env[0], ("FOO".to_owned(), "bar".to_owned()));
+        // Only the first '=' separates key from value.
+        assert_eq!(env[1], ("BAZ".to_owned(), "qux=quux".to_owned()));
+    }
+
+    #[test]
+    fn parse_env_rejects_missing_equals() {
+        assert!(parse_env(&["NOTANENV".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn calendar_template_selects_dict_or_array() {
+        let reg = Handlebars::new();
+        let entry = json!({ "Hour": 9 });
+
+        let single = reg
+            .render_template(
+                PLIST_CALENDAR_TEMPLATE,
+                &json!({
+                    "name": "t", "program_path": "/bin/true", "args": [],
+                    "stdout": "o", "stderr": "e", "working_dir": "/",
+                    "calendar": [entry.clone()], "multiple": false,
+                }),
+            )
+            .unwrap();
+        assert!(single.contains("<key>StartCalendarInterval</key>"));
+        assert!(single.contains("<dict>"));
+        assert!(!single.contains("<array>\n        <dict>"));
+
+        let multiple = reg
+            .render_template(
+                PLIST_CALENDAR_TEMPLATE,
+                &json!({
+                    "name": "t", "program_path": "/bin/true", "args": [],
+                    "stdout": "o", "stderr": "e", "working_dir": "/",
+                    "calendar": [entry.clone(), entry], "multiple": true,
+                }),
+            )
+            .unwrap();
+        assert!(multiple.contains("<array>\n        <dict>"));
     }
 }